@@ -0,0 +1,163 @@
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::{Batch, IncubationSession};
+
+/// Full record of a session as written to/read from a JSON backup. Carries
+/// `export_key` rather than `id` (see `IncubationSession::export_key`).
+#[derive(Serialize, Deserialize)]
+pub struct ExportedSession {
+    pub export_key: String,
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub batches: Vec<Batch>,
+    pub reminders_enabled: bool,
+}
+
+impl From<&IncubationSession> for ExportedSession {
+    fn from(session: &IncubationSession) -> Self {
+        Self {
+            export_key: session.export_key.clone(),
+            name: session.name.clone(),
+            start_date: session.start_date,
+            batches: session.batches.clone(),
+            reminders_enabled: session.reminders_enabled,
+        }
+    }
+}
+
+/// Serializes every session as a single JSON document for backup/sharing.
+pub fn to_json(sessions: &[IncubationSession]) -> serde_json::Result<String> {
+    let exported: Vec<ExportedSession> = sessions.iter().map(ExportedSession::from).collect();
+    serde_json::to_string_pretty(&exported)
+}
+
+/// Parses a JSON document produced by [`to_json`] (or hand-edited in the same shape).
+pub fn from_json(contents: &str) -> serde_json::Result<Vec<ExportedSession>> {
+    serde_json::from_str(contents)
+}
+
+/// Flattens every session to one CSV row per batch, for opening in a spreadsheet.
+pub fn to_csv(sessions: &[IncubationSession]) -> String {
+    let mut csv = String::from("session_name,species,description,egg_count,start_date,hatch_date\n");
+    for session in sessions {
+        let hatch_date = session.final_hatch_date();
+        for batch in &session.batches {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                csv_escape(&session.name),
+                batch.species.canonical_name(),
+                csv_escape(&batch.description),
+                batch.egg_count,
+                session.start_date,
+                hatch_date,
+            ));
+        }
+    }
+    csv
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Which action a resolved [`FileDialogState`] path should be handled as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogKind {
+    ExportJson,
+    ExportCsv,
+    Import,
+}
+
+/// Tracks a native file dialog opened on its own thread, the way objdiff does
+/// it, so the blocking OS picker never stalls the egui frame loop. `poll`
+/// drains the result once the user closes the dialog.
+pub struct FileDialogState {
+    pending: Option<(DialogKind, Receiver<Option<PathBuf>>)>,
+}
+
+impl FileDialogState {
+    pub fn new() -> Self {
+        Self { pending: None }
+    }
+
+    /// Opens a native "save as" dialog pre-filled with `default_file_name`,
+    /// tagged as `kind` for when [`poll`](Self::poll) resolves it.
+    pub fn request_save(&mut self, kind: DialogKind, default_file_name: &str) {
+        let (tx, rx) = mpsc::channel();
+        let default_file_name = default_file_name.to_string();
+        thread::spawn(move || {
+            let path = rfd::FileDialog::new().set_file_name(&default_file_name).save_file();
+            let _ = tx.send(path);
+        });
+        self.pending = Some((kind, rx));
+    }
+
+    /// Opens a native "open file" dialog, tagged as `kind`.
+    pub fn request_open(&mut self, kind: DialogKind) {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let path = rfd::FileDialog::new().pick_file();
+            let _ = tx.send(path);
+        });
+        self.pending = Some((kind, rx));
+    }
+
+    /// Returns the dialog's outcome once the user has closed it (`Some(path)`
+    /// if they picked a file, `None` if they cancelled). Returns `None` while
+    /// no dialog is in flight or it's still open. Call once per frame.
+    pub fn poll(&mut self) -> Option<(DialogKind, Option<PathBuf>)> {
+        let (_, rx) = self.pending.as_ref()?;
+        match rx.try_recv() {
+            Ok(path) => {
+                let (kind, _) = self.pending.take().unwrap();
+                Some((kind, path))
+            }
+            Err(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Species;
+
+    #[test]
+    fn json_round_trip_preserves_session_fields() {
+        let sessions = vec![IncubationSession {
+            id: 1,
+            name: "Batch A".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            batches: vec![Batch { species: Species::Anatra, description: "duck eggs".to_string(), egg_count: 10 }],
+            reminders_enabled: true,
+            export_key: "fixed-uuid".to_string(),
+        }];
+
+        let json = to_json(&sessions).unwrap();
+        let imported = from_json(&json).unwrap();
+
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0].export_key, sessions[0].export_key);
+        assert_eq!(imported[0].name, sessions[0].name);
+        assert_eq!(imported[0].start_date, sessions[0].start_date);
+        assert_eq!(imported[0].reminders_enabled, sessions[0].reminders_enabled);
+        assert_eq!(imported[0].batches.len(), 1);
+        assert_eq!(imported[0].batches[0].egg_count, 10);
+    }
+
+    #[test]
+    fn csv_escapes_commas_and_quotes() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape("a\"b"), "\"a\"\"b\"");
+    }
+}
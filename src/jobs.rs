@@ -0,0 +1,49 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use crate::persistence::PersistenceResult;
+use crate::updater::UpdateCheckResult;
+
+/// Results of work handed off to a [`JobQueue`], tagged by which job produced them.
+/// New subsystems that need background work add a variant here rather than
+/// spinning up their own channel type.
+pub enum JobResult {
+    CheckUpdate(UpdateCheckResult),
+    Persistence(PersistenceResult),
+}
+
+/// Runs jobs on worker threads and lets the UI drain finished results once per
+/// frame, so `update()` never blocks on I/O.
+pub struct JobQueue<T> {
+    sender: Sender<T>,
+    receiver: Receiver<T>,
+}
+
+impl<T: Send + 'static> JobQueue<T> {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self { sender, receiver }
+    }
+
+    /// Runs `job` on a new thread and sends its result back through the queue.
+    pub fn spawn<F>(&self, job: F)
+    where
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let sender = self.sender.clone();
+        thread::spawn(move || {
+            let _ = sender.send(job());
+        });
+    }
+
+    /// Returns every result that has arrived since the last call. Call once per frame.
+    pub fn drain(&self) -> Vec<T> {
+        self.receiver.try_iter().collect()
+    }
+
+    /// Clones the sending half, for long-lived workers that push results
+    /// outside of a one-shot `spawn` closure.
+    pub fn sender(&self) -> Sender<T> {
+        self.sender.clone()
+    }
+}
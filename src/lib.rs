@@ -1,140 +1,119 @@
+mod export;
+mod jobs;
+mod localization;
+mod persistence;
+mod reminders;
+mod updater;
+
+use std::collections::HashSet;
+use std::fs;
+
 use chrono::{Duration, NaiveDate, Utc};
 use eframe::{egui, App, Frame};
 use egui::{CentralPanel, Color32, Context, RichText, Stroke, TextEdit, TopBottomPanel};
 use rusqlite::{Connection, Result, ToSql};
 use serde::{Deserialize, Serialize};
 
+use export::{DialogKind, FileDialogState};
+use jobs::{JobQueue, JobResult};
+use localization::LocalizationManager;
+use persistence::{PersistenceResult, PersistenceWorker};
+use updater::UpdateInfo;
+
 const DB_PATH: &str = "incubator_sessions.db";
 const APP_NAME: &str = "gestore_incubatrice_gui";
-
-#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
-pub enum Language {
-    Italian,
-    English,
-}
-
-impl std::fmt::Display for Language {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Language::Italian => write!(f, "Italiano"),
-            Language::English => write!(f, "English"),
-        }
-    }
-}
+const DEFAULT_LANGUAGE: &str = "it";
 
 #[derive(Serialize, Deserialize)]
 pub struct Settings {
-    pub language: Language,
+    pub language: String,
 }
 
 impl Default for Settings {
     fn default() -> Self {
-        Self { language: Language::Italian }
+        Self { language: DEFAULT_LANGUAGE.to_string() }
     }
 }
 
-pub struct Localization {
-    app_title: String,
-    create_session_window_title: String,
-    info_window_title: String,
-    session_name_label: String,
-    add_egg_batches_label: String,
-    no_active_sessions_label: String,
-    started_on_label: String,
-    hatch_on_label: String,
-    status_label: String,
-    day_label: String,
-    batches_in_session_label: String,
-    new_session_button: String,
-    add_another_batch_button: String,
-    create_and_start_button: String,
-    cancel_button: String,
-    delete_button: String,
-    info_button: String,
-    preferences_button: String,
-    close_button: String,
-    description_hint: String,
-    version_label: String,
-    license_label: String,
-    source_code_link: String,
-    author_label: String,
-}
-
-impl Localization {
-    fn new(lang: Language) -> Self {
-        match lang {
-            Language::Italian => Self {
-                app_title: "Gestore Incubate Miste".to_string(),
-                create_session_window_title: "Crea Nuova Incubata Mista".to_string(),
-                info_window_title: "Informazioni".to_string(),
-                session_name_label: "Nome Incubata:".to_string(),
-                add_egg_batches_label: "Aggiungi Lotti di Uova:".to_string(),
-                no_active_sessions_label: "Nessuna incubata attiva. Clicca su 'Nuova Incubata' per iniziare.".to_string(),
-                started_on_label: "Iniziata il".to_string(),
-                hatch_on_label: "Schiusa prevista".to_string(),
-                status_label: "Stato".to_string(),
-                day_label: "Giorno".to_string(),
-                batches_in_session_label: "Lotti in questa incubata:".to_string(),
-                new_session_button: "🐣 Nuova Incubata".to_string(),
-                add_another_batch_button: "+ Aggiungi un altro lotto".to_string(),
-                create_and_start_button: "Crea e Avvia Incubata".to_string(),
-                cancel_button: "Annulla".to_string(),
-                delete_button: "🗑 Elimina".to_string(),
-                info_button: "Info".to_string(),
-                preferences_button: "Preferenze".to_string(),
-                close_button: "Chiudi".to_string(),
-                description_hint: "Descrizione (es. Marans)".to_string(),
-                version_label: "Versione".to_string(),
-                license_label: "Licenza".to_string(),
-                source_code_link: "Visita il codice sorgente su GitHub".to_string(),
-                author_label: "Autore".to_string(),
-            },
-            Language::English => Self {
-                app_title: "Mixed Batch Incubator".to_string(),
-                create_session_window_title: "Create New Mixed Batch".to_string(),
-                info_window_title: "About".to_string(),
-                session_name_label: "Batch Name:".to_string(),
-                add_egg_batches_label: "Add Egg Batches:".to_string(),
-                no_active_sessions_label: "No active sessions. Click 'New Batch' to start.".to_string(),
-                started_on_label: "Started on".to_string(),
-                hatch_on_label: "Expected hatch".to_string(),
-                status_label: "Status".to_string(),
-                day_label: "Day".to_string(),
-                batches_in_session_label: "Batches in this session:".to_string(),
-                new_session_button: "🐣 New Batch".to_string(),
-                add_another_batch_button: "+ Add another batch".to_string(),
-                create_and_start_button: "Create and Start Batch".to_string(),
-                cancel_button: "Cancel".to_string(),
-                delete_button: "🗑 Delete".to_string(),
-                info_button: "About".to_string(),
-                preferences_button: "Preferences".to_string(),
-                close_button: "Close".to_string(),
-                description_hint: "Description (e.g., Marans)".to_string(),
-                version_label: "Version".to_string(),
-                license_label: "License".to_string(),
-                source_code_link: "Visit source code on GitHub".to_string(),
-                author_label: "Author".to_string(),
-            },
-        }
-    }
+/// Directory alongside the user's config where they can drop extra
+/// `<code>.json` locale files without recompiling.
+fn user_locales_dir() -> Option<std::path::PathBuf> {
+    confy::get_configuration_file_path(APP_NAME, None)
+        .ok()
+        .and_then(|path| path.parent().map(|dir| dir.join("locales")))
 }
 
 #[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum Species { Gallina, Anatra, Quaglia, Oca }
 impl Species {
     fn incubation_days(&self) -> i64 { match self { Self::Gallina => 21, Self::Anatra => 28, Self::Quaglia => 18, Self::Oca => 30 } }
-}
-impl std::fmt::Display for Species {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { write!(f, "{:?}", self) }
+
+    /// Stable, locale-independent name for data interchange (CSV export),
+    /// as opposed to [`LocalizationManager::species_name`] which is for display.
+    pub(crate) fn canonical_name(&self) -> &'static str {
+        match self {
+            Self::Gallina => "Chicken",
+            Self::Anatra => "Duck",
+            Self::Quaglia => "Quail",
+            Self::Oca => "Goose",
+        }
+    }
 }
 #[derive(Clone, Serialize, Deserialize)]
-pub struct Batch { species: Species, description: String, egg_count: u32 }
+pub struct Batch { pub(crate) species: Species, pub(crate) description: String, pub(crate) egg_count: u32 }
 #[derive(Clone)]
-pub struct IncubationSession { id: i64, name: String, start_date: NaiveDate, batches: Vec<Batch> }
+pub struct IncubationSession {
+    pub(crate) id: i64,
+    pub(crate) name: String,
+    pub(crate) start_date: NaiveDate,
+    pub(crate) batches: Vec<Batch>,
+    pub(crate) reminders_enabled: bool,
+    /// Stable identity used for JSON export/import. Unlike `id` (this row's
+    /// local autoincrement primary key, which two different installs can
+    /// easily assign to unrelated sessions), `export_key` is a UUID, so
+    /// importing a backup can never collide with an unrelated local session.
+    pub(crate) export_key: String,
+}
 impl IncubationSession {
     fn max_incubation_days(&self) -> i64 { self.batches.iter().map(|b| b.species.incubation_days()).max().unwrap_or(0) }
-    fn final_hatch_date(&self) -> NaiveDate { self.start_date + Duration::days(self.max_incubation_days()) }
+    pub(crate) fn final_hatch_date(&self) -> NaiveDate { self.start_date + Duration::days(self.max_incubation_days()) }
     fn current_session_day(&self) -> i64 { (Utc::now().date_naive() - self.start_date).num_days() + 1 }
+    fn day_to_add(&self, batch: &Batch) -> i64 { self.max_incubation_days() - batch.species.incubation_days() + 1 }
+
+    fn status(&self) -> SessionStatus {
+        let current_day = self.current_session_day();
+        if current_day > self.max_incubation_days() {
+            SessionStatus::Completed
+        } else if self.batches.iter().any(|b| current_day == self.day_to_add(b)) {
+            SessionStatus::HatchingToday
+        } else {
+            SessionStatus::InProgress
+        }
+    }
+
+    /// Matches `query` against the session name and each batch description,
+    /// using glob syntax when `query` parses as a glob and falling back to a
+    /// plain case-insensitive substring match otherwise.
+    fn matches_search(&self, query: &str) -> bool {
+        if query.is_empty() {
+            return true;
+        }
+        if let Ok(matcher) = globset::Glob::new(query).map(|g| g.compile_matcher()) {
+            if matcher.is_match(&self.name) || self.batches.iter().any(|b| matcher.is_match(&b.description)) {
+                return true;
+            }
+        }
+        let query = query.to_lowercase();
+        self.name.to_lowercase().contains(&query)
+            || self.batches.iter().any(|b| b.description.to_lowercase().contains(&query))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SessionStatus {
+    HatchingToday,
+    InProgress,
+    Completed,
 }
 
 pub struct IncubatorApp {
@@ -144,62 +123,185 @@ pub struct IncubatorApp {
     new_session_name: String,
     new_session_batches: Vec<Batch>,
     settings: Settings,
-    localization: Localization,
+    localization: LocalizationManager,
+    job_queue: JobQueue<JobResult>,
+    check_update_running: bool,
+    latest_update: Option<UpdateInfo>,
+    update_error: Option<String>,
+    persistence: PersistenceWorker,
+    persist_running: bool,
+    session_search: String,
+    filter_hatching_today: bool,
+    filter_in_progress: bool,
+    filter_completed: bool,
+    notified_events: HashSet<(i64, String)>,
+    last_reminder_check: Option<NaiveDate>,
+    sessions_loaded: bool,
+    reminders_dirty: bool,
+    file_dialog: FileDialogState,
+    io_message: Option<String>,
 }
 
 impl IncubatorApp {
     fn new() -> Self {
-        let conn = open_db_connection();
-        init_db(&conn).expect("Creazione DB fallita");
-        let settings: Settings = confy::load(APP_NAME, None).unwrap_or_default();
-        let localization = Localization::new(settings.language);
+        let mut settings: Settings = confy::load(APP_NAME, None).unwrap_or_default();
+        let localization = LocalizationManager::load(user_locales_dir().as_deref(), &settings.language);
+        settings.language = localization.current_code().to_string();
+        let job_queue = JobQueue::new();
+        updater::queue_check_update(&job_queue);
+        let persistence = PersistenceWorker::spawn(job_queue.sender());
+        persistence.reload();
         Self {
-            sessions: load_sessions(&conn).expect("Caricamento sessioni fallito"),
+            sessions: vec![],
             show_new_session_window: false,
             show_about_window: false,
             new_session_name: String::new(),
             new_session_batches: vec![],
             settings,
             localization,
+            job_queue,
+            check_update_running: true,
+            latest_update: None,
+            update_error: None,
+            persistence,
+            persist_running: true,
+            session_search: String::new(),
+            filter_hatching_today: false,
+            filter_in_progress: false,
+            filter_completed: false,
+            notified_events: HashSet::new(),
+            last_reminder_check: None,
+            sessions_loaded: false,
+            reminders_dirty: false,
+            file_dialog: FileDialogState::new(),
+            io_message: None,
+        }
+    }
+
+    fn queue_check_update(&mut self) {
+        self.check_update_running = true;
+        self.update_error = None;
+        updater::queue_check_update(&self.job_queue);
+    }
+
+    /// Drains finished background jobs and merges their results into app state.
+    /// Called once per frame; never blocks.
+    fn process_job_results(&mut self) {
+        for result in self.job_queue.drain() {
+            match result {
+                JobResult::CheckUpdate(outcome) => {
+                    self.check_update_running = false;
+                    match outcome {
+                        Ok(update) => self.latest_update = update,
+                        Err(err) => self.update_error = Some(err),
+                    }
+                }
+                JobResult::Persistence(PersistenceResult::SessionsReloaded(outcome)) => {
+                    self.persist_running = false;
+                    if let Ok((sessions, notified_events)) = outcome {
+                        self.sessions = sessions;
+                        self.notified_events = notified_events;
+                        self.sessions_loaded = true;
+                        self.reminders_dirty = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Scans every reminder-enabled session for due candling/lockdown/hatch
+    /// events and fires a desktop notification for each one not already
+    /// recorded as notified. Runs once per calendar day, plus an extra time
+    /// whenever `self.sessions` just changed (`reminders_dirty`) — a session
+    /// added, imported, or edited later the same day can introduce a
+    /// same-day event that a pure date gate would miss until tomorrow.
+    ///
+    /// Waits for the first successful persistence reload before stamping the
+    /// day-gate: `sessions` starts empty on launch, and without this guard the
+    /// day's scan would run (and lock itself out) against that empty list
+    /// before the real sessions ever arrived.
+    fn check_reminders(&mut self) {
+        if !self.sessions_loaded {
+            return;
+        }
+        let today = Utc::now().date_naive();
+        if self.last_reminder_check == Some(today) && !self.reminders_dirty {
+            return;
+        }
+        self.last_reminder_check = Some(today);
+        self.reminders_dirty = false;
+
+        for (session, event) in reminders::due_events(&self.sessions, today, &self.notified_events) {
+            reminders::notify(session, event, &self.localization);
+            self.notified_events.insert((session.id, event.key().to_string()));
+            self.persistence.mark_notified(session.id, event.key().to_string());
         }
     }
 
     fn add_session(&mut self) {
         if !self.new_session_name.is_empty() && !self.new_session_batches.is_empty() {
-            let session = IncubationSession {
-                id: 0,
-                name: self.new_session_name.clone(),
-                start_date: Utc::now().date_naive(),
-                batches: self.new_session_batches.clone(),
-            };
-            let conn = open_db_connection();
-            if add_session_to_db(&conn, &session).is_ok() {
-                self.sessions = load_sessions(&conn).unwrap();
-            }
+            self.persistence.add_session(
+                self.new_session_name.clone(),
+                Utc::now().date_naive(),
+                self.new_session_batches.clone(),
+            );
+            self.persist_running = true;
             self.show_new_session_window = false;
             self.new_session_name.clear();
             self.new_session_batches.clear();
         }
     }
 
-    fn change_language(&mut self, lang: Language) {
-        self.settings.language = lang;
-        self.localization = Localization::new(lang);
+    fn change_language(&mut self, code: &str) {
+        self.localization.set_language(code);
+        self.settings.language = code.to_string();
         confy::store(APP_NAME, None, &self.settings).expect("Impossibile salvare le impostazioni");
     }
+
+    /// Handles the path chosen (if any) from a finished file dialog: writes the
+    /// export format `kind` asked for, or reads and upserts an import.
+    fn handle_dialog_result(&mut self, kind: DialogKind, path: std::path::PathBuf) {
+        self.io_message = Some(match kind {
+            DialogKind::ExportJson => export::to_json(&self.sessions)
+                .map_err(|e| e.to_string())
+                .and_then(|json| fs::write(&path, json).map_err(|e| e.to_string()))
+                .map(|()| format!("{} {}", self.localization.get("export_success_label"), self.sessions.len()))
+                .unwrap_or_else(|e| e),
+            DialogKind::ExportCsv => fs::write(&path, export::to_csv(&self.sessions))
+                .map_err(|e| e.to_string())
+                .map(|()| format!("{} {}", self.localization.get("export_success_label"), self.sessions.len()))
+                .unwrap_or_else(|e| e),
+            DialogKind::Import => fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|contents| export::from_json(&contents).map_err(|e| e.to_string()))
+                .map(|imported| {
+                    let count = imported.len();
+                    self.persistence.upsert_sessions(imported);
+                    self.persist_running = true;
+                    format!("{} {}", self.localization.get("import_success_label"), count)
+                })
+                .unwrap_or_else(|e| e),
+        });
+    }
 }
 
 impl App for IncubatorApp {
     fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        self.process_job_results();
+        self.check_reminders();
+        if let Some((kind, Some(path))) = self.file_dialog.poll() {
+            self.handle_dialog_result(kind, path);
+        }
+
         if self.show_new_session_window {
-            egui::Window::new(&self.localization.create_session_window_title)
+            egui::Window::new(self.localization.get("create_session_window_title"))
                 .collapsible(false)
                 .resizable(false)
                 .show(ctx, |ui| {
-                    ui.label(&self.localization.session_name_label);
+                    ui.label(self.localization.get("session_name_label"));
                     ui.text_edit_singleline(&mut self.new_session_name);
                     ui.separator();
-                    ui.label(&self.localization.add_egg_batches_label);
+                    ui.label(self.localization.get("add_egg_batches_label"));
 
                     if self.new_session_batches.is_empty() {
                         self.new_session_batches.push(Batch {
@@ -209,21 +311,25 @@ impl App for IncubatorApp {
                         });
                     }
 
+                    let species_label = self.localization.get("species_label");
                     let mut batch_to_remove = None;
                     for (i, batch) in self.new_session_batches.iter_mut().enumerate() {
                         ui.horizontal(|ui| {
-                            egui::ComboBox::from_label(format!("Specie {}", i + 1))
-                                .selected_text(format!("{}", batch.species))
+                            egui::ComboBox::from_label(format!("{} {}", species_label, i + 1))
+                                .selected_text(self.localization.species_name(batch.species))
                                 .show_ui(ui, |ui| {
-                                    ui.selectable_value(&mut batch.species, Species::Gallina, "Gallina");
-                                    ui.selectable_value(&mut batch.species, Species::Anatra, "Anatra");
-                                    ui.selectable_value(&mut batch.species, Species::Quaglia, "Quaglia");
-                                    ui.selectable_value(&mut batch.species, Species::Oca, "Oca");
+                                    for species in [Species::Gallina, Species::Anatra, Species::Quaglia, Species::Oca] {
+                                        ui.selectable_value(
+                                            &mut batch.species,
+                                            species,
+                                            self.localization.species_name(species),
+                                        );
+                                    }
                                 });
 
                             ui.add(egui::DragValue::new(&mut batch.egg_count).clamp_range(1..=100).prefix("Uova: "));
                             let text_edit_widget = TextEdit::singleline(&mut batch.description)
-                                .hint_text(&self.localization.description_hint);
+                                .hint_text(self.localization.get("description_hint"));
                             ui.add(text_edit_widget);
 
                             if ui.button("🗑").clicked() {
@@ -236,7 +342,7 @@ impl App for IncubatorApp {
                     }
 
                     ui.add_space(5.0);
-                    if ui.button(&self.localization.add_another_batch_button).clicked() {
+                    if ui.button(self.localization.get("add_another_batch_button")).clicked() {
                         self.new_session_batches.push(Batch {
                             species: Species::Gallina,
                             description: String::new(),
@@ -246,68 +352,140 @@ impl App for IncubatorApp {
 
                     ui.separator();
                     ui.horizontal(|ui| {
-                        if ui.button(&self.localization.create_and_start_button).clicked() {
-                            self.add_session();
-                        }
-                        if ui.button(&self.localization.cancel_button).clicked() {
+                        ui.add_enabled_ui(!self.persist_running, |ui| {
+                            if ui.button(self.localization.get("create_and_start_button")).clicked() {
+                                self.add_session();
+                            }
+                        });
+                        if ui.button(self.localization.get("cancel_button")).clicked() {
                             self.show_new_session_window = false;
                         }
+                        if self.persist_running {
+                            ui.spinner();
+                        }
                     });
                 });
         }
 
         if self.show_about_window {
-            egui::Window::new(&self.localization.info_window_title)
+            egui::Window::new(self.localization.get("info_window_title"))
                 .collapsible(false)
                 .resizable(false)
                 .show(ctx, |ui| {
-                    ui.label(format!("{}: 1.0.0", &self.localization.version_label));
-                    ui.label(format!("{}: {}", &self.localization.license_label, env!("CARGO_PKG_LICENSE")));
-                    ui.label(format!("{}: minomitrugno", &self.localization.author_label));
-                    ui.hyperlink_to(&self.localization.source_code_link, "https://github.com/minomitrugno/incubator-control");
-                    if ui.button(&self.localization.close_button).clicked() {
+                    ui.label(format!("{}: {}", self.localization.get("version_label"), env!("CARGO_PKG_VERSION")));
+                    ui.label(format!("{}: {}", self.localization.get("license_label"), env!("CARGO_PKG_LICENSE")));
+                    ui.label(format!("{}: minomitrugno", self.localization.get("author_label")));
+                    ui.hyperlink_to(self.localization.get("source_code_link"), "https://github.com/minomitrugno/incubator-control");
+
+                    ui.separator();
+                    if let Some(update) = self.latest_update.clone() {
+                        ui.colored_label(
+                            Color32::GREEN,
+                            format!("{} {}", self.localization.get("update_available_label"), update.tag_name),
+                        );
+                        if ui.button(self.localization.get("open_release_button")).clicked() {
+                            ctx.open_url(egui::OpenUrl::new_tab(&update.html_url));
+                        }
+                    } else if self.check_update_running {
+                        ui.spinner();
+                    } else if ui.button(self.localization.get("check_update_button")).clicked() {
+                        self.queue_check_update();
+                    }
+                    if let Some(err) = &self.update_error {
+                        ui.colored_label(Color32::RED, err);
+                    }
+
+                    ui.separator();
+                    if ui.button(self.localization.get("close_button")).clicked() {
                         self.show_about_window = false;
                     }
                 });
         }
 
-        let mut selected_language: Option<Language> = None;
+        let mut selected_language: Option<String> = None;
         TopBottomPanel::bottom("footer")
             .show(ctx, |ui| {
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    if ui.button(&self.localization.info_button).clicked() {
+                    if ui.button(self.localization.get("info_button")).clicked() {
                         self.show_about_window = true;
                     }
-                    ui.menu_button(&self.localization.preferences_button, |ui| {
-                        if ui.button("Italiano").clicked() {
-                            selected_language = Some(Language::Italian);
+                    ui.menu_button(self.localization.get("preferences_button"), |ui| {
+                        for (code, display_name) in self.localization.available_languages() {
+                            if ui.button(display_name).clicked() {
+                                selected_language = Some(code);
+                            }
+                        }
+                    });
+                    ui.menu_button(self.localization.get("data_button"), |ui| {
+                        if ui.button(self.localization.get("export_json_button")).clicked() {
+                            self.file_dialog.request_save(DialogKind::ExportJson, "incubator_sessions.json");
+                            ui.close_menu();
                         }
-                        if ui.button("English").clicked() {
-                            selected_language = Some(Language::English);
+                        if ui.button(self.localization.get("export_csv_button")).clicked() {
+                            self.file_dialog.request_save(DialogKind::ExportCsv, "incubator_sessions.csv");
+                            ui.close_menu();
+                        }
+                        if ui.button(self.localization.get("import_button")).clicked() {
+                            self.file_dialog.request_open(DialogKind::Import);
+                            ui.close_menu();
                         }
                     });
+                    if let Some(message) = &self.io_message {
+                        ui.label(message);
+                    }
                 });
             });
-        if let Some(lang) = selected_language {
-            self.change_language(lang);
+        if let Some(code) = selected_language {
+            self.change_language(&code);
         }
 
         CentralPanel::default().show(ctx, |ui| {
             ui.horizontal(|ui| {
-                ui.heading(&self.localization.app_title);
-                if ui.button(&self.localization.new_session_button).clicked() {
+                ui.heading(self.localization.get("app_title"));
+                if ui.button(self.localization.get("new_session_button")).clicked() {
                     self.show_new_session_window = true;
                 }
             });
             ui.separator();
 
+            ui.horizontal(|ui| {
+                ui.add(
+                    TextEdit::singleline(&mut self.session_search)
+                        .hint_text(self.localization.get("search_hint")),
+                );
+                ui.toggle_value(&mut self.filter_hatching_today, self.localization.get("filter_hatching_today_label"));
+                ui.toggle_value(&mut self.filter_in_progress, self.localization.get("filter_in_progress_label"));
+                ui.toggle_value(&mut self.filter_completed, self.localization.get("filter_completed_label"));
+            });
+            ui.separator();
+
+            let any_status_filter = self.filter_hatching_today || self.filter_in_progress || self.filter_completed;
+            let visible_sessions: Vec<&IncubationSession> = self
+                .sessions
+                .iter()
+                .filter(|session| session.matches_search(&self.session_search))
+                .filter(|session| {
+                    if !any_status_filter {
+                        return true;
+                    }
+                    match session.status() {
+                        SessionStatus::HatchingToday => self.filter_hatching_today,
+                        SessionStatus::InProgress => self.filter_in_progress,
+                        SessionStatus::Completed => self.filter_completed,
+                    }
+                })
+                .collect();
+
             if self.sessions.is_empty() {
-                ui.label(&self.localization.no_active_sessions_label);
+                ui.label(self.localization.get("no_active_sessions_label"));
+            } else if visible_sessions.is_empty() {
+                ui.label(self.localization.get("no_matching_sessions_label"));
             }
 
             egui::ScrollArea::vertical().show(ui, |ui| {
                 let mut session_to_remove: Option<i64> = None;
-                for session in &self.sessions {
+                let mut reminders_toggle: Option<(i64, bool)> = None;
+                for session in visible_sessions {
                     let max_days = session.max_incubation_days();
                     let current_day = session.current_session_day();
                     let progress = if max_days > 0 { (current_day as f32) / (max_days as f32) } else { 0.0 };
@@ -317,39 +495,49 @@ impl App for IncubatorApp {
                         ui.horizontal(|ui| {
                             ui.heading(RichText::new(&session.name).size(20.0));
                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                                if ui.button(&self.localization.delete_button).clicked() {
-                                    session_to_remove = Some(session.id);
-                                }
+                                ui.add_enabled_ui(!self.persist_running, |ui| {
+                                    if ui.button(self.localization.get("delete_button")).clicked() {
+                                        session_to_remove = Some(session.id);
+                                    }
+                                });
                             });
                         });
                         ui.label(format!(
                             "{}: {}. {}: {}",
-                            &self.localization.started_on_label,
+                            self.localization.get("started_on_label"),
                             session.start_date.format("%d/%m/%Y"),
-                            &self.localization.hatch_on_label,
+                            self.localization.get("hatch_on_label"),
                             session.final_hatch_date().format("%d/%m/%Y")
                         ));
 
                         ui.add_space(5.0);
                         ui.label(format!(
                             "{}: {}",
-                            &self.localization.status_label,
+                            self.localization.get("status_label"),
                             current_day.max(0)
                         ));
                         ui.add(egui::ProgressBar::new(progress.clamp(0.0, 1.0)).show_percentage());
+
+                        let mut reminders_enabled = session.reminders_enabled;
+                        ui.add_enabled_ui(!self.persist_running, |ui| {
+                            if ui.checkbox(&mut reminders_enabled, self.localization.get("reminders_enabled_label")).changed() {
+                                reminders_toggle = Some((session.id, reminders_enabled));
+                            }
+                        });
                         ui.add_space(10.0);
 
-                        ui.label(RichText::new(&self.localization.batches_in_session_label).strong());
+                        ui.label(RichText::new(self.localization.get("batches_in_session_label")).strong());
 
                         for batch in &session.batches {
-                            let day_to_add = max_days - batch.species.incubation_days() + 1;
+                            let day_to_add = session.day_to_add(batch);
+                            let species_name = self.localization.species_name(batch.species);
                             let text: RichText;
 
                             if current_day == day_to_add {
                                 text = RichText::new(format!(
                                     "➡️ {}: {} ({})",
-                                    &self.localization.day_label,
-                                    batch.species,
+                                    self.localization.get("day_label"),
+                                    species_name,
                                     batch.description
                                 ))
                                 .color(Color32::GREEN)
@@ -358,17 +546,17 @@ impl App for IncubatorApp {
                             } else if current_day < day_to_add {
                                 text = RichText::new(format!(
                                     "⏳ {} {} ({}) {} {}",
-                                    &self.localization.add_egg_batches_label,
-                                    batch.species,
+                                    self.localization.get("add_egg_batches_label"),
+                                    species_name,
                                     batch.description,
-                                    &self.localization.day_label,
+                                    self.localization.get("day_label"),
                                     day_to_add
                                 ))
                                 .color(Color32::GRAY);
                             } else {
                                 text = RichText::new(format!(
                                     "✅ {} {} ({})",
-                                    batch.species, batch.description, batch.egg_count
+                                    species_name, batch.description, batch.egg_count
                                 ))
                                 .color(Color32::from_rgb(100, 150, 100));
                             }
@@ -379,21 +567,23 @@ impl App for IncubatorApp {
                 }
 
                 if let Some(id) = session_to_remove {
-                    let conn = open_db_connection();
-                    if remove_session_from_db(&conn, id).is_ok() {
-                        self.sessions.retain(|s| s.id != id);
-                    }
+                    self.persistence.remove_session(id);
+                    self.persist_running = true;
+                }
+                if let Some((id, enabled)) = reminders_toggle {
+                    self.persistence.set_reminders_enabled(id, enabled);
+                    self.persist_running = true;
                 }
             });
         });
     }
 }
 
-fn open_db_connection() -> Connection {
+pub(crate) fn open_db_connection() -> Connection {
     Connection::open(DB_PATH).expect("Connessione DB fallita")
 }
 
-fn init_db(conn: &Connection) -> Result<()> {
+pub(crate) fn init_db(conn: &Connection) -> Result<()> {
     conn.execute(
         "CREATE TABLE IF NOT EXISTS sessions (
             id          INTEGER PRIMARY KEY,
@@ -403,25 +593,81 @@ fn init_db(conn: &Connection) -> Result<()> {
         )",
         (),
     )?;
+    // Older databases predate this column; ignore the error if it's already there.
+    let _ = conn.execute("ALTER TABLE sessions ADD COLUMN reminders_enabled INTEGER NOT NULL DEFAULT 1", ());
+    // See IncubationSession::export_key for why this isn't just `id`.
+    let _ = conn.execute("ALTER TABLE sessions ADD COLUMN export_key TEXT", ());
+    let _ = conn.execute("CREATE UNIQUE INDEX IF NOT EXISTS idx_sessions_export_key ON sessions(export_key)", ());
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS notified_events (
+            session_id  INTEGER NOT NULL,
+            event_key   TEXT NOT NULL,
+            PRIMARY KEY (session_id, event_key)
+        )",
+        (),
+    )?;
     Ok(())
 }
 
-fn add_session_to_db(conn: &Connection, session: &IncubationSession) -> Result<i64> {
+pub(crate) fn add_session_to_db(conn: &Connection, session: &IncubationSession) -> Result<i64> {
     let batches_json = serde_json::to_string(&session.batches).unwrap();
 
     conn.execute(
-        "INSERT INTO sessions (name, start_date, batches) VALUES (?1, ?2, ?3)",
-        &[&session.name as &dyn ToSql, &session.start_date, &batches_json],
+        "INSERT INTO sessions (name, start_date, batches, reminders_enabled, export_key) VALUES (?1, ?2, ?3, ?4, ?5)",
+        &[
+            &session.name as &dyn ToSql,
+            &session.start_date,
+            &batches_json,
+            &session.reminders_enabled,
+            &session.export_key,
+        ],
     )?;
     Ok(conn.last_insert_rowid())
 }
 
-fn remove_session_from_db(conn: &Connection, id: i64) -> Result<usize> {
+/// Inserts a session as a new row, or overwrites the existing row sharing its
+/// `export_key`. Used by JSON import (see [`IncubationSession::export_key`]).
+pub(crate) fn upsert_session_into_db(
+    conn: &Connection,
+    export_key: &str,
+    name: &str,
+    start_date: NaiveDate,
+    batches: &[Batch],
+    reminders_enabled: bool,
+) -> Result<()> {
+    let batches_json = serde_json::to_string(batches).unwrap();
+
+    conn.execute(
+        "INSERT INTO sessions (export_key, name, start_date, batches, reminders_enabled) VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(export_key) DO UPDATE SET
+             name = excluded.name,
+             start_date = excluded.start_date,
+             batches = excluded.batches,
+             reminders_enabled = excluded.reminders_enabled",
+        (export_key, name, &start_date, &batches_json, &reminders_enabled),
+    )?;
+    Ok(())
+}
+
+pub(crate) fn remove_session_from_db(conn: &Connection, id: i64) -> Result<usize> {
     conn.execute("DELETE FROM sessions WHERE id = ?1", [id])
 }
 
-fn load_sessions(conn: &Connection) -> Result<Vec<IncubationSession>> {
-    let mut stmt = conn.prepare("SELECT id, name, start_date, batches FROM sessions ORDER BY start_date DESC")?;
+pub(crate) fn set_reminders_enabled(conn: &Connection, id: i64, enabled: bool) -> Result<usize> {
+    conn.execute("UPDATE sessions SET reminders_enabled = ?1 WHERE id = ?2", (enabled, id))
+}
+
+pub(crate) fn mark_event_notified(conn: &Connection, session_id: i64, event_key: &str) -> Result<usize> {
+    conn.execute(
+        "INSERT OR IGNORE INTO notified_events (session_id, event_key) VALUES (?1, ?2)",
+        (session_id, event_key),
+    )
+}
+
+pub(crate) fn load_sessions(conn: &Connection) -> Result<Vec<IncubationSession>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, name, start_date, batches, reminders_enabled, export_key FROM sessions ORDER BY start_date DESC",
+    )?;
     let session_iter = stmt.query_map([], |row| {
         let batches_json: String = row.get(3)?;
         let batches: Vec<Batch> = serde_json::from_str(&batches_json).unwrap_or_else(|_| vec![]);
@@ -431,6 +677,8 @@ fn load_sessions(conn: &Connection) -> Result<Vec<IncubationSession>> {
             name: row.get(1)?,
             start_date: row.get(2)?,
             batches,
+            reminders_enabled: row.get(4)?,
+            export_key: row.get::<_, Option<String>>(5)?.unwrap_or_default(),
         })
     })?;
 
@@ -438,9 +686,34 @@ fn load_sessions(conn: &Connection) -> Result<Vec<IncubationSession>> {
     for session in session_iter {
         sessions.push(session?);
     }
+
+    // Databases written before export_key existed (or rows inserted by older
+    // code) have it unset; backfill a fresh UUID for each one so every
+    // session has a stable identity before it can be exported.
+    for session in sessions.iter_mut() {
+        if session.export_key.is_empty() {
+            session.export_key = uuid::Uuid::new_v4().to_string();
+            conn.execute(
+                "UPDATE sessions SET export_key = ?1 WHERE id = ?2",
+                (&session.export_key, session.id),
+            )?;
+        }
+    }
+
     Ok(sessions)
 }
 
+pub(crate) fn load_notified_events(conn: &Connection) -> Result<std::collections::HashSet<(i64, String)>> {
+    let mut stmt = conn.prepare("SELECT session_id, event_key FROM notified_events")?;
+    let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+
+    let mut notified = std::collections::HashSet::new();
+    for row in rows {
+        notified.insert(row?);
+    }
+    Ok(notified)
+}
+
 pub fn start() {
     let native_options = eframe::NativeOptions::default();
     eframe::run_native(
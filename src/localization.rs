@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::Species;
+
+const BUNDLED_LOCALES: &[(&str, &str)] = &[
+    ("it", include_str!("../locales/it.json")),
+    ("en", include_str!("../locales/en.json")),
+];
+
+const DEFAULT_LOCALE: &str = "it";
+
+#[derive(Deserialize)]
+struct LocaleFile {
+    language_name: String,
+    #[serde(flatten)]
+    strings: HashMap<String, String>,
+}
+
+/// Loads locale files into key→string maps and serves lookups by key, falling
+/// back to the default locale for anything a user-supplied file leaves out.
+/// Adding a language is just dropping another `<code>.json` file into the
+/// user locales directory given to [`load`](Self::load); no enum or match arm
+/// needs to change.
+pub struct LocalizationManager {
+    locales: HashMap<String, LocaleFile>,
+    current: String,
+}
+
+impl LocalizationManager {
+    /// Loads the bundled locales plus any `*.json` files found in
+    /// `user_locales_dir`, then activates `initial` (or the default locale if
+    /// `initial` isn't among the loaded codes).
+    pub fn load(user_locales_dir: Option<&Path>, initial: &str) -> Self {
+        let mut locales = HashMap::new();
+
+        for (code, contents) in BUNDLED_LOCALES {
+            if let Ok(file) = serde_json::from_str::<LocaleFile>(contents) {
+                locales.insert((*code).to_string(), file);
+            }
+        }
+
+        if let Some(dir) = user_locales_dir {
+            if let Ok(entries) = fs::read_dir(dir) {
+                for entry in entries.flatten() {
+                    let path = entry.path();
+                    if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                        continue;
+                    }
+                    let Some(code) = path.file_stem().and_then(|s| s.to_str()) else {
+                        continue;
+                    };
+                    if let Ok(contents) = fs::read_to_string(&path) {
+                        if let Ok(file) = serde_json::from_str::<LocaleFile>(&contents) {
+                            locales.insert(code.to_string(), file);
+                        }
+                    }
+                }
+            }
+        }
+
+        let current = if locales.contains_key(initial) {
+            initial.to_string()
+        } else {
+            DEFAULT_LOCALE.to_string()
+        };
+
+        Self { locales, current }
+    }
+
+    pub fn set_language(&mut self, code: &str) {
+        if self.locales.contains_key(code) {
+            self.current = code.to_string();
+        }
+    }
+
+    pub fn current_code(&self) -> &str {
+        &self.current
+    }
+
+    /// All loaded locales as `(code, display_name)` pairs, for rendering one
+    /// button per entry in the preferences menu.
+    pub fn available_languages(&self) -> Vec<(String, String)> {
+        let mut languages: Vec<(String, String)> = self
+            .locales
+            .iter()
+            .map(|(code, file)| (code.clone(), file.language_name.clone()))
+            .collect();
+        languages.sort_by(|a, b| a.1.cmp(&b.1));
+        languages
+    }
+
+    /// Looks up `key` in the active locale, falls back to the default locale,
+    /// and finally to the key itself so a missing translation stays visible
+    /// instead of silently vanishing.
+    pub fn get(&self, key: &str) -> String {
+        self.locales
+            .get(&self.current)
+            .and_then(|file| file.strings.get(key))
+            .or_else(|| self.locales.get(DEFAULT_LOCALE).and_then(|file| file.strings.get(key)))
+            .cloned()
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    pub fn species_name(&self, species: Species) -> String {
+        let key = match species {
+            Species::Gallina => "species_gallina",
+            Species::Anatra => "species_anatra",
+            Species::Quaglia => "species_quaglia",
+            Species::Oca => "species_oca",
+        };
+        self.get(key)
+    }
+}
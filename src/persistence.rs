@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+
+use chrono::NaiveDate;
+
+use crate::export::ExportedSession;
+use crate::jobs::JobResult;
+use crate::{
+    add_session_to_db, init_db, load_notified_events, load_sessions, mark_event_notified,
+    open_db_connection, remove_session_from_db, set_reminders_enabled, upsert_session_into_db,
+    Batch, IncubationSession,
+};
+
+enum PersistenceCommand {
+    AddSession {
+        name: String,
+        start_date: NaiveDate,
+        batches: Vec<Batch>,
+    },
+    RemoveSession {
+        id: i64,
+    },
+    SetRemindersEnabled {
+        id: i64,
+        enabled: bool,
+    },
+    MarkNotified {
+        session_id: i64,
+        event_key: String,
+    },
+    UpsertSessions {
+        sessions: Vec<ExportedSession>,
+    },
+    Reload,
+}
+
+pub enum PersistenceResult {
+    SessionsReloaded(Result<(Vec<IncubationSession>, HashSet<(i64, String)>), String>),
+}
+
+/// Owns a single long-lived SQLite connection on a worker thread and serializes
+/// every write and reload through a command channel, so adds/deletes never
+/// stall a frame the way a fresh `Connection` per action used to.
+pub struct PersistenceWorker {
+    command_tx: Sender<PersistenceCommand>,
+}
+
+impl PersistenceWorker {
+    pub fn spawn(result_tx: Sender<JobResult>) -> Self {
+        let (command_tx, command_rx) = mpsc::channel::<PersistenceCommand>();
+        thread::spawn(move || {
+            let conn = open_db_connection();
+            init_db(&conn).expect("Creazione DB fallita");
+
+            for command in command_rx {
+                // MarkNotified is fire-and-forget: the UI already applied it
+                // optimistically, so there's nothing to reload for it.
+                if let PersistenceCommand::MarkNotified { session_id, event_key } = &command {
+                    let _ = mark_event_notified(&conn, *session_id, event_key);
+                    continue;
+                }
+
+                let outcome = match command {
+                    PersistenceCommand::AddSession { name, start_date, batches } => {
+                        let session = IncubationSession {
+                            id: 0,
+                            name,
+                            start_date,
+                            batches,
+                            reminders_enabled: true,
+                            export_key: uuid::Uuid::new_v4().to_string(),
+                        };
+                        add_session_to_db(&conn, &session).map(|_| ()).map_err(|e| e.to_string())
+                    }
+                    PersistenceCommand::RemoveSession { id } => remove_session_from_db(&conn, id)
+                        .map(|_| ())
+                        .map_err(|e| e.to_string()),
+                    PersistenceCommand::SetRemindersEnabled { id, enabled } => {
+                        set_reminders_enabled(&conn, id, enabled).map(|_| ()).map_err(|e| e.to_string())
+                    }
+                    PersistenceCommand::MarkNotified { .. } => unreachable!("handled above"),
+                    PersistenceCommand::UpsertSessions { sessions } => sessions
+                        .into_iter()
+                        .try_for_each(|exported| {
+                            upsert_session_into_db(
+                                &conn,
+                                &exported.export_key,
+                                &exported.name,
+                                exported.start_date,
+                                &exported.batches,
+                                exported.reminders_enabled,
+                            )
+                        })
+                        .map_err(|e| e.to_string()),
+                    PersistenceCommand::Reload => Ok(()),
+                };
+
+                let reloaded = outcome.and_then(|()| {
+                    let sessions = load_sessions(&conn).map_err(|e| e.to_string())?;
+                    let notified = load_notified_events(&conn).map_err(|e| e.to_string())?;
+                    Ok((sessions, notified))
+                });
+                let _ = result_tx.send(JobResult::Persistence(PersistenceResult::SessionsReloaded(reloaded)));
+            }
+        });
+        Self { command_tx }
+    }
+
+    pub fn add_session(&self, name: String, start_date: NaiveDate, batches: Vec<Batch>) {
+        let _ = self.command_tx.send(PersistenceCommand::AddSession { name, start_date, batches });
+    }
+
+    pub fn remove_session(&self, id: i64) {
+        let _ = self.command_tx.send(PersistenceCommand::RemoveSession { id });
+    }
+
+    pub fn set_reminders_enabled(&self, id: i64, enabled: bool) {
+        let _ = self.command_tx.send(PersistenceCommand::SetRemindersEnabled { id, enabled });
+    }
+
+    pub fn mark_notified(&self, session_id: i64, event_key: String) {
+        let _ = self.command_tx.send(PersistenceCommand::MarkNotified { session_id, event_key });
+    }
+
+    /// Upserts every imported session by its `export_key` and reloads the
+    /// list, so an imported backup goes through the same path as any other
+    /// write.
+    pub fn upsert_sessions(&self, sessions: Vec<ExportedSession>) {
+        let _ = self.command_tx.send(PersistenceCommand::UpsertSessions { sessions });
+    }
+
+    pub fn reload(&self) {
+        let _ = self.command_tx.send(PersistenceCommand::Reload);
+    }
+}
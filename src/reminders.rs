@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+
+use chrono::{Duration, NaiveDate};
+
+use crate::localization::LocalizationManager;
+use crate::IncubationSession;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReminderEvent {
+    Candling7,
+    Candling14,
+    Lockdown,
+    HatchDay,
+}
+
+impl ReminderEvent {
+    const ALL: [ReminderEvent; 4] = [Self::Candling7, Self::Candling14, Self::Lockdown, Self::HatchDay];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Candling7 => "candling_day7",
+            Self::Candling14 => "candling_day14",
+            Self::Lockdown => "lockdown",
+            Self::HatchDay => "hatch_day",
+        }
+    }
+
+    fn due_date(&self, session: &IncubationSession) -> NaiveDate {
+        match self {
+            Self::Candling7 => session.start_date + Duration::days(6),
+            Self::Candling14 => session.start_date + Duration::days(13),
+            Self::Lockdown => session.final_hatch_date() - Duration::days(3),
+            Self::HatchDay => session.final_hatch_date(),
+        }
+    }
+
+    /// Localized notification title for this event, via `localization`
+    /// rather than a hardcoded English string.
+    fn title(&self, localization: &LocalizationManager) -> String {
+        let key = match self {
+            Self::Candling7 => "reminder_candling7_title",
+            Self::Candling14 => "reminder_candling14_title",
+            Self::Lockdown => "reminder_lockdown_title",
+            Self::HatchDay => "reminder_hatch_day_title",
+        };
+        localization.get(key)
+    }
+}
+
+/// Every event across `sessions` whose due date has arrived, hasn't already
+/// been recorded in `notified`, and belongs to a session with reminders on.
+pub fn due_events<'a>(
+    sessions: &'a [IncubationSession],
+    today: NaiveDate,
+    notified: &HashSet<(i64, String)>,
+) -> Vec<(&'a IncubationSession, ReminderEvent)> {
+    sessions
+        .iter()
+        .filter(|session| session.reminders_enabled)
+        .flat_map(|session| {
+            ReminderEvent::ALL.iter().filter_map(move |event| {
+                let due = today >= event.due_date(session) && !notified.contains(&(session.id, event.key().to_string()));
+                due.then_some((session, *event))
+            })
+        })
+        .collect()
+}
+
+/// Fires an OS desktop notification for `event` on `session`. Best-effort:
+/// a failure here has nothing useful to recover to, so it's swallowed rather
+/// than surfaced in the UI.
+pub fn notify(session: &IncubationSession, event: ReminderEvent, localization: &LocalizationManager) {
+    let _ = notify_rust::Notification::new()
+        .summary(&session.name)
+        .body(&event.title(localization))
+        .show();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Batch, Species};
+
+    fn chicken_session(start_date: NaiveDate, reminders_enabled: bool) -> IncubationSession {
+        IncubationSession {
+            id: 1,
+            name: "Test batch".to_string(),
+            start_date,
+            batches: vec![Batch { species: Species::Gallina, description: String::new(), egg_count: 6 }],
+            reminders_enabled,
+            export_key: "test-key".to_string(),
+        }
+    }
+
+    #[test]
+    fn due_dates_are_relative_to_start_and_hatch() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let session = chicken_session(start, true);
+
+        assert_eq!(ReminderEvent::Candling7.due_date(&session), start + Duration::days(6));
+        assert_eq!(ReminderEvent::Candling14.due_date(&session), start + Duration::days(13));
+        assert_eq!(ReminderEvent::Lockdown.due_date(&session), session.final_hatch_date() - Duration::days(3));
+        assert_eq!(ReminderEvent::HatchDay.due_date(&session), session.final_hatch_date());
+    }
+
+    #[test]
+    fn due_events_skips_sessions_with_reminders_disabled() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let session = chicken_session(start, false);
+        let today = session.final_hatch_date();
+
+        assert!(due_events(&[session], today, &HashSet::new()).is_empty());
+    }
+
+    #[test]
+    fn due_events_skips_events_already_notified() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let session = chicken_session(start, true);
+        let today = session.final_hatch_date();
+        let mut notified = HashSet::new();
+        notified.insert((session.id, ReminderEvent::HatchDay.key().to_string()));
+
+        let due = due_events(&[session], today, &notified);
+        assert!(due.iter().all(|(_, event)| *event != ReminderEvent::HatchDay));
+        assert!(due.iter().any(|(_, event)| *event == ReminderEvent::Lockdown));
+    }
+
+    #[test]
+    fn due_events_excludes_events_not_yet_due() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let session = chicken_session(start, true);
+        let today = start;
+
+        assert!(due_events(&[session], today, &HashSet::new()).is_empty());
+    }
+}
@@ -0,0 +1,79 @@
+use serde::Deserialize;
+
+use crate::jobs::{JobQueue, JobResult};
+
+const LATEST_RELEASE_API: &str =
+    "https://api.github.com/repos/minomitrugno/incubator-control/releases/latest";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateInfo {
+    pub tag_name: String,
+    pub html_url: String,
+}
+
+pub type UpdateCheckResult = Result<Option<UpdateInfo>, String>;
+
+/// Queues a "check update" job on `queue`. Runs entirely on a worker thread so
+/// the egui render loop never waits on the network.
+pub fn queue_check_update(queue: &JobQueue<JobResult>) {
+    queue.spawn(|| JobResult::CheckUpdate(check_for_update()));
+}
+
+/// Queries the GitHub releases API for the latest tag and compares it against
+/// the version this binary was built with.
+fn check_for_update() -> UpdateCheckResult {
+    let release: UpdateInfo = ureq::get(LATEST_RELEASE_API)
+        .call()
+        .map_err(|e| e.to_string())?
+        .into_json()
+        .map_err(|e| e.to_string())?;
+
+    if is_newer(&release.tag_name, env!("CARGO_PKG_VERSION")) {
+        Ok(Some(release))
+    } else {
+        Ok(None)
+    }
+}
+
+fn is_newer(remote_tag: &str, current: &str) -> bool {
+    let parse = |v: &str| -> Vec<u32> {
+        v.trim_start_matches('v')
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    };
+    let mut remote = parse(remote_tag);
+    let mut current = parse(current);
+    // Pad the shorter version to the other's length with trailing zeros, so
+    // "1.0" and "1.0.0" compare equal instead of the missing component
+    // making the shorter one look older.
+    let len = remote.len().max(current.len());
+    remote.resize(len, 0);
+    current.resize(len, 0);
+    remote > current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_length_versions() {
+        assert!(is_newer("1.2.0", "1.1.0"));
+        assert!(!is_newer("1.1.0", "1.2.0"));
+        assert!(!is_newer("1.1.0", "1.1.0"));
+    }
+
+    #[test]
+    fn padded_length_versions_compare_equal() {
+        assert!(!is_newer("1.0.0", "1.0"));
+        assert!(!is_newer("1.0", "1.0.0"));
+        assert!(is_newer("1.0.1", "1.0"));
+    }
+
+    #[test]
+    fn v_prefixed_versions() {
+        assert!(is_newer("v1.2.0", "1.1.0"));
+        assert!(!is_newer("v1.1.0", "v1.1.0"));
+    }
+}